@@ -1,5 +1,5 @@
 use crate::no_std::ext::AnyExt1;
-use std::{prelude::v1::*, io::stdin, ops::BitXorAssign, time::{Instant, Duration}};
+use std::{prelude::v1::*, fmt, io::{self, stdin, stdout, Write}, ops::BitXorAssign, process::{Command, ExitStatus, Stdio}, str::FromStr, time::{Instant, Duration}};
 
 /// Reads a line of input from the standard input stream.
 ///
@@ -32,6 +32,39 @@ pub fn wait_enter() {
     while read_line() != None {}
 }
 
+/// Prints `msg` (without a trailing newline), flushes stdout, then reads and parses a line via [`read_line`].
+///
+/// Returns `None` on empty input or a parse failure.
+pub fn prompt<T: FromStr>(msg: &str) -> Option<T> {
+    print!("{msg}");
+    stdout().flush().ok()?;
+    read_parse()
+}
+
+/// Reads a line via [`read_line`] and parses it as `T`.
+///
+/// Returns `None` on empty input or a parse failure.
+pub fn read_parse<T: FromStr>() -> Option<T> {
+    read_line()?.parse().ok()
+}
+
+/// Repeatedly [`prompt`]s until `validate` accepts the parsed value, then returns it.
+///
+/// Returns `None` as soon as stdin is closed (EOF), rather than retrying forever.
+pub fn prompt_retry<T: FromStr>(msg: &str, validate: impl Fn(&T) -> bool) -> Option<T> {
+    loop {
+        print!("{msg}");
+        stdout().flush().ok()?;
+
+        let mut line = String::new();
+        if stdin().read_line(&mut line).ok()? == 0 { return None; }
+
+        if let Ok(value) = line.trim_end().parse() {
+            if validate(&value) { return Some(value); }
+        }
+    }
+}
+
 /// Swaps two variables' value.
 /// 
 /// # Examples
@@ -48,6 +81,47 @@ pub fn swap_xor<T>(a: &mut T, b: &mut T) where T: BitXorAssign<T> + Copy {
     *a ^= *b;
 }
 
+/// Raises the process's soft limit on open file descriptors to the hard limit, returning the new limit.
+///
+/// On macOS the new limit is also capped at `kern.maxfilesperproc`, and the hard limit is lowered to that cap.
+///
+/// On platforms without rlimits this is a no-op and returns `None`.
+#[cfg(unix)]
+pub fn raise_fd_limit() -> Option<u64> {
+    unsafe {
+        let mut rlim = std::mem::zeroed::<libc::rlimit>();
+        if libc::getrlimit(libc::RLIMIT_NOFILE, &mut rlim) != 0 { return None; }
+
+        #[cfg(target_os = "macos")]
+        {
+            let mut maxfiles: libc::c_int = 0;
+            let mut size = std::mem::size_of::<libc::c_int>();
+            let mib = [libc::CTL_KERN, libc::KERN_MAXFILESPERPROC];
+            let ret = libc::sysctl(
+                mib.as_ptr() as *mut _, mib.len() as u32,
+                &mut maxfiles as *mut _ as *mut _, &mut size,
+                std::ptr::null_mut(), 0,
+            );
+            if ret == 0 && (maxfiles as u64) < rlim.rlim_max {
+                rlim.rlim_max = maxfiles as u64;
+            }
+        }
+
+        rlim.rlim_cur = rlim.rlim_max;
+        if libc::setrlimit(libc::RLIMIT_NOFILE, &rlim) != 0 { return None; }
+
+        Some(rlim.rlim_cur as u64)
+    }
+}
+
+/// Raises the process's soft limit on open file descriptors to the hard limit, returning the new limit.
+///
+/// On platforms without rlimits this is a no-op and returns `None`.
+#[cfg(not(unix))]
+pub fn raise_fd_limit() -> Option<u64> {
+    None
+}
+
 /// Executes the given closure block and returns the duration of elapsed time interval.
 pub fn measure_time<R>(f: impl FnOnce() -> R) -> Duration {
     Instant::now().also_ref(|_| f()).elapsed()
@@ -59,16 +133,241 @@ pub fn measure_time_with_value<R>(f: impl FnOnce() -> R) -> (R, Duration) {
     Instant::now().let_owned(|s| (f(), s.elapsed()))
 }
 
+/// Statistics gathered from running a closure over multiple iterations. See [`bench`] and [`bench_with_warmup`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BenchStats {
+    pub min: Duration,
+    pub max: Duration,
+    pub mean: Duration,
+    pub median: Duration,
+    pub std_dev: Duration,
+    pub p90: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+impl BenchStats {
+    fn from_nanos(mut nanos: Vec<u128>) -> Option<Self> {
+        let n = nanos.len();
+        if n == 0 { return None; }
+
+        // Welford's single-pass mean/variance.
+        let mut mean = 0.0;
+        let mut m2 = 0.0;
+        for (i, &x) in nanos.iter().enumerate() {
+            let delta = x as f64 - mean;
+            mean += delta / (i + 1) as f64;
+            m2 += delta * (x as f64 - mean);
+        }
+        let mean_nanos = mean as u128;
+        let std_dev_nanos = (m2 / n as f64).sqrt() as u128;
+
+        nanos.sort_unstable();
+        let percentile = |p: f64| nanos[(p / 100.0 * (n - 1) as f64).round() as usize];
+
+        Some(BenchStats {
+            min: Duration::from_nanos(nanos[0] as u64),
+            max: Duration::from_nanos(nanos[n - 1] as u64),
+            mean: Duration::from_nanos(mean_nanos as u64),
+            median: Duration::from_nanos(percentile(50.0) as u64),
+            std_dev: Duration::from_nanos(std_dev_nanos as u64),
+            p90: Duration::from_nanos(percentile(90.0) as u64),
+            p95: Duration::from_nanos(percentile(95.0) as u64),
+            p99: Duration::from_nanos(percentile(99.0) as u64),
+        })
+    }
+}
+
+/// Runs the given closure `iters` times and returns statistics over the per-iteration elapsed time.
+///
+/// Returns `None` when `iters` is `0`.
+pub fn bench<R>(iters: usize, f: impl FnMut() -> R) -> Option<BenchStats> {
+    bench_with_warmup(0, iters, f)
+}
+
+/// Like [`bench`], but runs `warmup` extra iterations first and discards their timings.
+///
+/// Returns `None` when `iters` is `0`.
+pub fn bench_with_warmup<R>(warmup: usize, iters: usize, mut f: impl FnMut() -> R) -> Option<BenchStats> {
+    if iters == 0 { return None; }
+
+    for _ in 0..warmup { f(); }
+
+    let nanos = (0..iters)
+        .map(|_| measure_time(&mut f).as_nanos())
+        .collect::<Vec<_>>();
+
+    BenchStats::from_nanos(nanos)
+}
+
+/// Builder for a terminal progress line, produced by [`track`].
+///
+/// Configure it with [`Progress::label`] / [`Progress::throttle`], then call [`Progress::track`]
+/// to wrap an iterator.
+pub struct Progress {
+    label: String,
+    throttle: Duration,
+}
+
+impl Default for Progress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Progress {
+    /// Creates a builder with no label and a `100ms` redraw throttle.
+    pub fn new() -> Self {
+        Progress { label: String::new(), throttle: Duration::from_millis(100) }
+    }
+
+    /// Sets the text printed before the percentage on each redraw.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// Sets the minimum interval between redraws, to avoid flooding stdout.
+    pub fn throttle(mut self, interval: Duration) -> Self {
+        self.throttle = interval;
+        self
+    }
+
+    /// Wraps `iter` so that consuming items redraws a live progress line
+    /// (percentage, items done/total, elapsed, and an ETA extrapolated from `elapsed * remaining / done`),
+    /// at most once per throttle interval, plus always once `total` items have been consumed.
+    /// The line is cleared when the returned [`Track`] is dropped.
+    pub fn track<I: Iterator>(self, iter: I, total: usize) -> Track<I> {
+        Track {
+            iter, total,
+            done: 0,
+            start: Instant::now(),
+            last_draw: None,
+            label: self.label,
+            throttle: self.throttle,
+        }
+    }
+}
+
+/// An iterator adapter that renders a live terminal progress line as it's consumed. See [`track`].
+pub struct Track<I> {
+    iter: I,
+    total: usize,
+    done: usize,
+    start: Instant,
+    last_draw: Option<Instant>,
+    label: String,
+    throttle: Duration,
+}
+
+impl<I> Track<I> {
+    fn draw(&mut self, force: bool) {
+        let now = Instant::now();
+        if !force && self.last_draw.is_some_and(|t| now.duration_since(t) < self.throttle) { return; }
+        self.last_draw = Some(now);
+
+        let elapsed = self.start.elapsed();
+        let pct = if self.total == 0 { 100.0 } else { self.done as f64 / self.total as f64 * 100.0 };
+        let eta = if self.done == 0 {
+            Duration::ZERO
+        } else {
+            elapsed.mul_f64((self.total.saturating_sub(self.done)) as f64 / self.done as f64)
+        };
+
+        print!("\r\x1b[2K{}{pct:.1}% {}/{} elapsed {elapsed:.2?} eta {eta:.2?}", self.label, self.done, self.total);
+        let _ = stdout().flush();
+    }
+}
+
+impl<I: Iterator> Iterator for Track<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next();
+        if item.is_some() {
+            self.done += 1;
+            self.draw(self.done >= self.total);
+        }
+        item
+    }
+}
+
+impl<I> Drop for Track<I> {
+    fn drop(&mut self) {
+        print!("\r\x1b[2K");
+        let _ = stdout().flush();
+    }
+}
+
+/// Wraps `iter` (of the given `total` length) in a [`Track`] using the default [`Progress`] settings.
+///
+/// Use [`Progress::new`] directly to set a label or redraw throttle first.
+pub fn track<I: Iterator>(iter: I, total: usize) -> Track<I> {
+    Progress::new().track(iter, total)
+}
+
+/// A unit of time, used to [`convert`](TimeUnit::convert) a [`Duration`] into a plain `u128` count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeUnit {
+    Nanos,
+    Micros,
+    Millis,
+    Secs,
+    Mins,
+    Hours,
+}
+
+impl TimeUnit {
+    /// Converts `elapsed` into this unit, truncating towards zero.
+    pub fn convert(self, elapsed: Duration) -> u128 {
+        match self {
+            TimeUnit::Nanos => elapsed.as_nanos(),
+            TimeUnit::Micros => elapsed.as_micros(),
+            TimeUnit::Millis => elapsed.as_millis(),
+            TimeUnit::Secs => elapsed.as_secs() as u128,
+            TimeUnit::Mins => elapsed.as_secs() as u128 / 60,
+            TimeUnit::Hours => elapsed.as_secs() as u128 / 3600,
+        }
+    }
+}
+
+/// Error returned by [`TimeUnit`]'s [`FromStr`] impl when the string isn't a recognized unit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseTimeUnitError(String);
+
+impl fmt::Display for ParseTimeUnitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unsupported time unit: `{}`", self.0)
+    }
+}
+
+impl std::error::Error for ParseTimeUnitError {}
+
+impl FromStr for TimeUnit {
+    type Err = ParseTimeUnitError;
+
+    fn from_str(u: &str) -> Result<Self, Self::Err> {
+        match u {
+            "nanos" => Ok(TimeUnit::Nanos),
+            "micros" => Ok(TimeUnit::Micros),
+            "millis" => Ok(TimeUnit::Millis),
+            "secs" => Ok(TimeUnit::Secs),
+            "mins" => Ok(TimeUnit::Mins),
+            "hours" => Ok(TimeUnit::Hours),
+            _ => Err(ParseTimeUnitError(u.to_string())),
+        }
+    }
+}
+
 /// Takes a `&str` time unit as a parameter,
 /// returns conversion function.
+///
+/// # Panics
+///
+/// Panics if `u` isn't a unit recognized by [`TimeUnit`]'s [`FromStr`] impl.
 pub fn time_conversion(u: &str) -> impl FnOnce(Duration) -> u128 {
-    match u {
-        "nanos" => |elapsed: Duration| elapsed.as_nanos(),
-        "micros" => |elapsed: Duration| elapsed.as_micros(),
-        "millis" => |elapsed: Duration| elapsed.as_millis(),
-        "secs" => |elapsed: Duration| elapsed.as_secs() as u128,
-        _ => panic!("unsupported unit")
-    }
+    let unit: TimeUnit = u.parse().unwrap_or_else(|_| panic!("unsupported unit"));
+    move |elapsed| unit.convert(elapsed)
 }
 
 /// Takes a `String` time unit as a parameter,
@@ -76,3 +375,64 @@ pub fn time_conversion(u: &str) -> impl FnOnce(Duration) -> u128 {
 pub fn time_conversion_with_unit(u: String) -> (impl FnOnce(Duration) -> u128, String) {
     time_conversion(&u).let_owned(|f| (f, u))
 }
+
+/// Formats `d` using the largest sensible unit, e.g. `1.50s`, `340ms`, `2m 5s`.
+///
+/// # Examples
+/// ```
+/// use aoko::standard::fun::*;
+/// use std::time::Duration;
+///
+/// assert_eq!(humanize(Duration::from_millis(340)), "340ms");
+/// assert_eq!(humanize(Duration::from_secs(125)), "2m 5s");
+/// ```
+pub fn humanize(d: Duration) -> String {
+    let secs = d.as_secs();
+    if secs >= 3600 {
+        format!("{}h {}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {}s", secs / 60, secs % 60)
+    } else if secs >= 1 {
+        format!("{:.2}s", d.as_secs_f64())
+    } else if d.subsec_millis() >= 1 {
+        format!("{}ms", d.as_millis())
+    } else if d.subsec_micros() >= 1 {
+        format!("{}\u{b5}s", d.as_micros())
+    } else {
+        format!("{}ns", d.as_nanos())
+    }
+}
+
+/// Runs `cmd` with `args` and returns its captured stdout, decoded lossily so non-UTF-8 output never errors.
+pub fn slurp(cmd: &str, args: &[&str]) -> io::Result<String> {
+    Command::new(cmd).args(args).output()
+        .map(|out| String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+/// Like [`slurp`], but also returns the exit status and captured stderr.
+pub fn slurp_status(cmd: &str, args: &[&str]) -> io::Result<(ExitStatus, String, String)> {
+    Command::new(cmd).args(args).output().map(|out| (
+        out.status,
+        String::from_utf8_lossy(&out.stdout).into_owned(),
+        String::from_utf8_lossy(&out.stderr).into_owned(),
+    ))
+}
+
+/// Runs `cmd` with `args`, writes `input` to its stdin, and returns its captured stdout,
+/// decoded lossily so non-UTF-8 output never errors.
+pub fn pipe(cmd: &str, args: &[&str], input: &str) -> io::Result<String> {
+    let mut child = Command::new(cmd).args(args)
+        .stdin(Stdio::piped()).stdout(Stdio::piped())
+        .spawn()?;
+
+    // Write stdin on its own thread: if the child fills its stdout pipe buffer before
+    // it has read all of stdin, writing stdin and reading stdout on the same thread deadlocks.
+    let mut stdin = child.stdin.take().unwrap();
+    let input = input.to_string();
+    let writer = std::thread::spawn(move || stdin.write_all(input.as_bytes()));
+
+    let output = child.wait_with_output()?;
+    writer.join().unwrap()?;
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}